@@ -28,6 +28,10 @@ pub struct Decoder<'i, E: Variant> {
     /// Block buffer used for non-block-aligned data.
     buffer: BlockBuffer,
 
+    /// Line-wrapping state, set when this decoder was constructed with
+    /// [`Decoder::new_wrapped`].
+    line_wrap: Option<LineWrap>,
+
     /// Phantom parameter for the Base64 encoding in use.
     encoding: PhantomData<E>,
 }
@@ -37,12 +41,7 @@ impl<'i, E: Variant> Decoder<'i, E> {
     /// (non-newline-delimited) Base64-encoded data.
     pub fn new(input: &'i [u8]) -> Result<Self, Error> {
         let remaining = if E::PADDED {
-            // TODO(tarcieri): validate that padding is well-formed with `validate_padding`
-            let (unpadded_len, err) = decode_padding(input)?;
-            if err != 0 {
-                return Err(Error::InvalidEncoding);
-            }
-
+            let unpadded_len = decode_padding(input)?;
             &input[..unpadded_len]
         } else {
             input
@@ -51,6 +50,43 @@ impl<'i, E: Variant> Decoder<'i, E> {
         Ok(Self {
             remaining,
             buffer: BlockBuffer::default(),
+            line_wrap: None,
+            encoding: PhantomData,
+        })
+    }
+
+    /// Create a new decoder for a byte slice containing Base64-encoded data
+    /// hard-wrapped at `line_width` Base64 characters per line, e.g. an RFC
+    /// 7468 PEM body or an OpenSSH key file.
+    ///
+    /// Both `\n` and `\r\n` line endings are accepted transparently; an
+    /// optional trailing line ending after the final line is allowed but
+    /// not required. Any stray whitespace found where a Base64 character
+    /// was expected is rejected as invalid encoding.
+    pub fn new_wrapped(input: &'i [u8], line_width: usize) -> Result<Self, Error> {
+        if line_width == 0 {
+            return Err(Error::InvalidLength);
+        }
+
+        let trimmed = strip_trailing_line_ending(input);
+
+        let remaining = if E::PADDED {
+            match trimmed {
+                [head @ .., b'=', b'='] if !head.is_empty() => &trimmed[..trimmed.len() - 2],
+                [head @ .., b'='] if !head.is_empty() => &trimmed[..trimmed.len() - 1],
+                _ => trimmed,
+            }
+        } else {
+            trimmed
+        };
+
+        Ok(Self {
+            remaining,
+            buffer: BlockBuffer::default(),
+            line_wrap: Some(LineWrap {
+                width: line_width,
+                col: 0,
+            }),
             encoding: PhantomData,
         })
     }
@@ -67,6 +103,10 @@ impl<'i, E: Variant> Decoder<'i, E> {
             return Err(Error::InvalidLength);
         }
 
+        if self.line_wrap.is_some() {
+            return self.decode_wrapped(out);
+        }
+
         let mut out_off = 0;
 
         if !self.buffer.is_empty() {
@@ -130,6 +170,114 @@ impl<'i, E: Variant> Decoder<'i, E> {
     pub fn is_finished(&self) -> bool {
         self.remaining.is_empty() && self.buffer.is_empty()
     }
+
+    /// Line-wrapped counterpart to [`Decoder::decode`].
+    ///
+    /// Wrapped input isn't contiguous enough for the aligned bulk decoding
+    /// above, so this pulls one Base64 block at a time (skipping line
+    /// breaks at the expected column) through the same [`BlockBuffer`]
+    /// used for the unaligned tail of the non-wrapped path.
+    fn decode_wrapped<'o>(&mut self, out: &'o mut [u8]) -> Result<&'o [u8], Error> {
+        let mut out_off = 0;
+
+        while out_off < out.len() {
+            if self.buffer.is_empty() {
+                let mut block = [0u8; 4];
+                let mut len = 0;
+
+                while len < 4 {
+                    match self.next_alphabet_byte()? {
+                        Some(byte) => {
+                            block[len] = byte;
+                            len += 1;
+                        }
+                        None => break,
+                    }
+                }
+
+                if len == 0 {
+                    break;
+                }
+
+                self.buffer.fill::<E::Unpadded>(&block[..len])?;
+            }
+
+            let bytes = self
+                .buffer
+                .take(out.len().checked_sub(out_off).ok_or(InvalidLength)?);
+
+            if bytes.is_empty() {
+                break;
+            }
+
+            out[out_off..][..bytes.len()].copy_from_slice(bytes);
+            out_off = out_off.checked_add(bytes.len()).ok_or(InvalidLength)?;
+        }
+
+        if out.len() == out_off {
+            Ok(out)
+        } else {
+            Err(InvalidLength)
+        }
+    }
+
+    /// Pull the next Base64 alphabet byte from `remaining`, transparently
+    /// skipping the line break expected once the current line reaches
+    /// `line_width` characters. Returns `None` once `remaining` is
+    /// exhausted.
+    fn next_alphabet_byte(&mut self) -> Result<Option<u8>, Error> {
+        let mut wrap = self.line_wrap.take().expect("line wrapping not enabled");
+
+        if wrap.col == wrap.width {
+            self.remaining = skip_line_ending(self.remaining)?;
+            wrap.col = 0;
+        }
+
+        let byte = match self.remaining.split_first() {
+            Some((&byte, rest)) => {
+                self.remaining = rest;
+                wrap.col += 1;
+                Some(byte)
+            }
+            None => None,
+        };
+
+        self.line_wrap = Some(wrap);
+        Ok(byte)
+    }
+}
+
+/// Per-line state tracked by a [`Decoder`] constructed with
+/// [`Decoder::new_wrapped`].
+#[derive(Copy, Clone)]
+struct LineWrap {
+    /// Number of Base64 characters per line.
+    width: usize,
+
+    /// Number of Base64 characters consumed on the current line.
+    col: usize,
+}
+
+/// Strip a single trailing `\n` or `\r\n` from `input`, if present.
+fn strip_trailing_line_ending(input: &[u8]) -> &[u8] {
+    match input {
+        [head @ .., b'\r', b'\n'] => head,
+        [head @ .., b'\n'] => head,
+        _ => input,
+    }
+}
+
+/// Skip a single leading `\n` or `\r\n` from `input`, returning the
+/// remainder. Returns `input` unchanged if it's empty (i.e. the line
+/// break expected at the end of the final line was already stripped), or
+/// an error if a non-empty `input` doesn't start with a line break.
+fn skip_line_ending(input: &[u8]) -> Result<&[u8], Error> {
+    match input {
+        [b'\r', b'\n', rest @ ..] => Ok(rest),
+        [b'\n', rest @ ..] => Ok(rest),
+        [] => Ok(input),
+        _ => Err(Error::InvalidEncoding),
+    }
 }
 
 /// Base64 decode buffer for a 1-block input.
@@ -183,7 +331,7 @@ impl BlockBuffer {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Base64, Base64Unpadded, Decoder};
+    use crate::{Base64, Base64Unpadded, Decoder, Error};
 
     /// Padded Base64-encoded example
     const PADDED_BASE64: &str =
@@ -236,4 +384,86 @@ mod tests {
             assert!(decoder.is_finished());
         }
     }
+
+    /// Message used by the `new_wrapped`/`decode_wrapped` tests below.
+    const WRAPPED_BIN: &[u8] = b"Hello, World! This is a test of line wrapping.";
+
+    /// `WRAPPED_BIN` encoded as padded Base64, hard-wrapped at 5 Base64
+    /// characters per line (deliberately not a multiple of 4, so Base64
+    /// groups straddle line breaks) using `\n`.
+    const WRAPPED_LF: &str = "SGVsb\nG8sIF\ndvcmx\nkISBU\naGlzI\nGlzIG\nEgdGV\nzdCBv\nZiBsa\nW5lIH\ndyYXB\nwaW5n\nLg==";
+
+    /// Same as `WRAPPED_LF`, but with a trailing `\n` after the final line.
+    const WRAPPED_LF_TRAILING: &str = "SGVsb\nG8sIF\ndvcmx\nkISBU\naGlzI\nGlzIG\nEgdGV\nzdCBv\nZiBsa\nW5lIH\ndyYXB\nwaW5n\nLg==\n";
+
+    /// Same as `WRAPPED_LF`, but with `\r\n` line endings.
+    const WRAPPED_CRLF: &str = "SGVsb\r\nG8sIF\r\ndvcmx\r\nkISBU\r\naGlzI\r\nGlzIG\r\nEgdGV\r\nzdCBv\r\nZiBsa\r\nW5lIH\r\ndyYXB\r\nwaW5n\r\nLg==";
+
+    /// Same as `WRAPPED_CRLF`, but with a trailing `\r\n` after the final
+    /// line.
+    const WRAPPED_CRLF_TRAILING: &str = "SGVsb\r\nG8sIF\r\ndvcmx\r\nkISBU\r\naGlzI\r\nGlzIG\r\nEgdGV\r\nzdCBv\r\nZiBsa\r\nW5lIH\r\ndyYXB\r\nwaW5n\r\nLg==\r\n";
+
+    /// `WRAPPED_LF` with the second character of the first line swapped
+    /// for a space, i.e. stray whitespace in the middle of a line.
+    const WRAPPED_LF_MID_LINE_SPACE: &str = "S Vsb\nG8sIF\ndvcmx\nkISBU\naGlzI\nGlzIG\nEgdGV\nzdCBv\nZiBsa\nW5lIH\ndyYXB\nwaW5n\nLg==";
+
+    /// `WRAPPED_LF` with the first line break removed, so the byte at the
+    /// line boundary is ordinary Base64 input rather than a line ending.
+    const WRAPPED_LF_MISSING_BREAK: &str = "SGVsbG8sIF\ndvcmx\nkISBU\naGlzI\nGlzIG\nEgdGV\nzdCBv\nZiBsa\nW5lIH\ndyYXB\nwaW5n\nLg==";
+
+    /// `WRAPPED_LF` with the first line break replaced by a bare `\r`,
+    /// which isn't a recognized line ending on its own.
+    const WRAPPED_BARE_CR: &str = "SGVsb\rG8sIF\ndvcmx\nkISBU\naGlzI\nGlzIG\nEgdGV\nzdCBv\nZiBsa\nW5lIH\ndyYXB\nwaW5n\nLg==";
+
+    /// Width used by the wrapped test vectors above: not a multiple of 4,
+    /// so a Base64 group is split across the line break partway through.
+    const WRAPPED_WIDTH: usize = 5;
+
+    fn decode_wrapped_ok(input: &str) {
+        let mut decoder = Decoder::<Base64>::new_wrapped(input.as_bytes(), WRAPPED_WIDTH).unwrap();
+        let mut buffer = [0u8; WRAPPED_BIN.len()];
+        assert_eq!(decoder.decode(&mut buffer).unwrap(), WRAPPED_BIN);
+        assert!(decoder.is_finished());
+    }
+
+    fn decode_wrapped_err(input: &str) {
+        let mut decoder = Decoder::<Base64>::new_wrapped(input.as_bytes(), WRAPPED_WIDTH).unwrap();
+        let mut buffer = [0u8; WRAPPED_BIN.len()];
+        assert_eq!(decoder.decode(&mut buffer), Err(Error::InvalidEncoding));
+    }
+
+    #[test]
+    fn decode_wrapped_lf() {
+        decode_wrapped_ok(WRAPPED_LF);
+    }
+
+    #[test]
+    fn decode_wrapped_lf_trailing_newline() {
+        decode_wrapped_ok(WRAPPED_LF_TRAILING);
+    }
+
+    #[test]
+    fn decode_wrapped_crlf() {
+        decode_wrapped_ok(WRAPPED_CRLF);
+    }
+
+    #[test]
+    fn decode_wrapped_crlf_trailing_newline() {
+        decode_wrapped_ok(WRAPPED_CRLF_TRAILING);
+    }
+
+    #[test]
+    fn decode_wrapped_rejects_mid_line_whitespace() {
+        decode_wrapped_err(WRAPPED_LF_MID_LINE_SPACE);
+    }
+
+    #[test]
+    fn decode_wrapped_rejects_missing_line_break() {
+        decode_wrapped_err(WRAPPED_LF_MISSING_BREAK);
+    }
+
+    #[test]
+    fn decode_wrapped_rejects_bare_cr() {
+        decode_wrapped_err(WRAPPED_BARE_CR);
+    }
 }
@@ -0,0 +1,87 @@
+//! `Display` adapter for Base64-encoding data on the fly.
+
+use crate::Encoding;
+use core::{fmt, marker::PhantomData};
+
+/// Size, in Base64 characters, of the stack buffer used to stage encoded
+/// output before it's written through the `Formatter`.
+const CHUNK_LEN: usize = 64;
+
+/// Number of input bytes which encode to at most [`CHUNK_LEN`] characters.
+const INPUT_CHUNK_LEN: usize = CHUNK_LEN / 4 * 3;
+
+/// Zero-allocation adapter which renders a byte slice as Base64 through
+/// [`fmt::Display`]/[`fmt::Debug`], encoding into a small stack buffer and
+/// writing through the [`Formatter`][`fmt::Formatter`] in fixed-size
+/// chunks.
+///
+/// This lets callers interpolate binary data as Base64 directly in
+/// `format!`/`write!` without an `alloc`-gated [`Encoding::encode_string`]
+/// call or sizing an output buffer by hand, and it works in `no_std`
+/// since it never allocates.
+pub struct Base64Display<'a, E: Encoding> {
+    bytes: &'a [u8],
+    encoding: PhantomData<E>,
+}
+
+impl<'a, E: Encoding> Base64Display<'a, E> {
+    /// Create a new `Base64Display` which renders `bytes` as Base64.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            encoding: PhantomData,
+        }
+    }
+}
+
+impl<E: Encoding> fmt::Display for Base64Display<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; CHUNK_LEN];
+
+        for chunk in self.bytes.chunks(INPUT_CHUNK_LEN) {
+            let encoded = E::encode(chunk, &mut buf).map_err(|_| fmt::Error)?;
+            f.write_str(encoded)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: Encoding> fmt::Debug for Base64Display<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        fmt::Display::fmt(self, f)?;
+        f.write_str("\"")
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{Base64Display, INPUT_CHUNK_LEN};
+    use crate::{Base64, Encoding};
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn matches_encode_string_around_chunk_boundary() {
+        // INPUT_CHUNK_LEN is the size at which Base64Display's internal
+        // buffer has to flush and start a new chunk, so lengths straddling
+        // it on either side are the ones most likely to expose an
+        // off-by-one in the chunking.
+        for len in INPUT_CHUNK_LEN.saturating_sub(2)..=INPUT_CHUNK_LEN * 2 + 2 {
+            let bytes: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let expected = Base64::encode_string(&bytes);
+            let actual = format!("{}", Base64Display::<Base64>::new(&bytes));
+            assert_eq!(actual, expected, "len={len}");
+        }
+    }
+
+    #[test]
+    fn debug_wraps_in_quotes() {
+        let bytes = [1, 2, 3];
+        let expected = format!("\"{}\"", Base64::encode_string(&bytes));
+        assert_eq!(
+            format!("{:?}", Base64Display::<Base64>::new(&bytes)),
+            expected
+        );
+    }
+}
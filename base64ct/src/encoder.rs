@@ -0,0 +1,383 @@
+//! Streaming incremental Base64 encoder.
+
+use crate::{variant::Variant, Error};
+use core::{marker::PhantomData, str};
+
+#[cfg(docsrs)]
+use crate::{Base64, Base64Unpadded};
+
+/// Stateful Base64 encoder with support for buffered, incremental encoding.
+///
+/// The `E` type parameter can be any type which impls [`Encoding`][`crate::Encoding`]
+/// such as [`Base64`] or [`Base64Unpadded`].
+///
+/// This is the write-side counterpart to [`Decoder`][`crate::Decoder`]: it
+/// lets callers produce Base64 output from data that arrives in chunks
+/// (e.g. serializing a message field by field) without buffering the whole
+/// input up front.
+pub struct Encoder<'o, E: Variant> {
+    /// Output buffer being written into.
+    output: &'o mut [u8],
+
+    /// Number of bytes of `output` written so far.
+    position: usize,
+
+    /// Block buffer used for input which isn't a multiple of 3 bytes.
+    buffer: BlockBuffer,
+
+    /// Line-wrapping state, set when this encoder was constructed with
+    /// [`Encoder::new_wrapped`].
+    line_wrap: Option<LineWrap>,
+
+    /// Phantom parameter for the Base64 encoding in use.
+    encoding: PhantomData<E>,
+}
+
+impl<'o, E: Variant> Encoder<'o, E> {
+    /// Create a new encoder which writes Base64-encoded output into the
+    /// given byte slice.
+    pub fn new(output: &'o mut [u8]) -> Self {
+        Self {
+            output,
+            position: 0,
+            buffer: BlockBuffer::default(),
+            line_wrap: None,
+            encoding: PhantomData,
+        }
+    }
+
+    /// Create a new encoder which hard-wraps its Base64 output at
+    /// `line_width` characters per line using `line_ending`, e.g. to
+    /// produce an RFC 7468 PEM body or an OpenSSH key file.
+    ///
+    /// [`DEFAULT_LINE_WIDTH`] is the common PEM line width of 64 Base64
+    /// characters.
+    pub fn new_wrapped(output: &'o mut [u8], line_width: usize, line_ending: LineEnding) -> Self {
+        Self {
+            output,
+            position: 0,
+            buffer: BlockBuffer::default(),
+            line_wrap: Some(LineWrap {
+                width: line_width,
+                ending: line_ending,
+                col: 0,
+            }),
+            encoding: PhantomData,
+        }
+    }
+
+    /// Encode the given input data, writing as much Base64 as is currently
+    /// available into the output buffer.
+    ///
+    /// Input which doesn't complete a 3-byte group is buffered internally
+    /// and encoded once enough data has arrived (or [`Encoder::finish`] is
+    /// called).
+    pub fn encode(&mut self, mut input: &[u8]) -> Result<(), Error> {
+        if !self.buffer.is_empty() {
+            let n = self.buffer.fill(input);
+            input = &input[n..];
+
+            if self.buffer.is_full() {
+                let group = self.buffer.take();
+                self.write_group(group)?;
+            } else {
+                return Ok(());
+            }
+        }
+
+        let mut chunks = input.chunks_exact(3);
+
+        for chunk in &mut chunks {
+            self.write_group([chunk[0], chunk[1], chunk[2]])?;
+        }
+
+        let rem = chunks.remainder();
+        debug_assert!(self.buffer.is_empty());
+        self.buffer.fill(rem);
+
+        Ok(())
+    }
+
+    /// Flush any buffered input, emitting padding if `E::PADDED`, and
+    /// return the Base64 written so far as a `&str`.
+    pub fn finish(mut self) -> Result<&'o str, Error> {
+        if !self.buffer.is_empty() {
+            let tail_chars = tail_len(self.buffer.length);
+            let tail = self.buffer.take();
+            let encoded = E::Unpadded::encode_group(tail);
+            self.write(&encoded[..tail_chars])?;
+
+            if E::PADDED {
+                self.write(&[b'=', b'='][..4 - tail_chars])?;
+            }
+        }
+
+        if let Some(wrap) = self.line_wrap {
+            if wrap.col > 0 {
+                self.write_raw(wrap.ending.as_bytes())?;
+            }
+        }
+
+        let position = self.position;
+        str::from_utf8(&self.output[..position]).map_err(|_| Error::InvalidEncoding)
+    }
+
+    /// Encode one complete 3-byte group, writing 4 Base64 characters.
+    fn write_group(&mut self, group: [u8; 3]) -> Result<(), Error> {
+        let encoded = E::Unpadded::encode_group(group);
+        self.write(&encoded)
+    }
+
+    /// Write Base64 output bytes, inserting a line ending at the
+    /// configured column if this encoder was constructed with
+    /// [`Encoder::new_wrapped`].
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        if self.line_wrap.is_some() {
+            for &byte in bytes {
+                let mut wrap = self.line_wrap.take().expect("line wrapping not enabled");
+
+                if wrap.col == wrap.width {
+                    self.write_raw(wrap.ending.as_bytes())?;
+                    wrap.col = 0;
+                }
+
+                wrap.col += 1;
+                self.line_wrap = Some(wrap);
+                self.write_raw(&[byte])?;
+            }
+
+            Ok(())
+        } else {
+            self.write_raw(bytes)
+        }
+    }
+
+    /// Write raw output bytes, advancing `position`.
+    fn write_raw(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let end = self
+            .position
+            .checked_add(bytes.len())
+            .ok_or(Error::InvalidLength)?;
+
+        if end > self.output.len() {
+            return Err(Error::InvalidLength);
+        }
+
+        self.output[self.position..end].copy_from_slice(bytes);
+        self.position = end;
+        Ok(())
+    }
+}
+
+/// Common PEM line width of 64 Base64 characters, for use with
+/// [`Encoder::new_wrapped`].
+pub const DEFAULT_LINE_WIDTH: usize = 64;
+
+/// Line ending used when wrapping Base64 output, e.g. for RFC 7468 PEM
+/// bodies or OpenSSH key files.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LineEnding {
+    /// `\n`
+    Lf,
+
+    /// `\r\n`
+    Crlf,
+}
+
+impl LineEnding {
+    /// Byte representation of this line ending.
+    pub fn as_bytes(self) -> &'static [u8] {
+        match self {
+            LineEnding::Lf => b"\n",
+            LineEnding::Crlf => b"\r\n",
+        }
+    }
+}
+
+/// Per-line state tracked by an [`Encoder`] constructed with
+/// [`Encoder::new_wrapped`].
+#[derive(Copy, Clone)]
+struct LineWrap {
+    /// Number of Base64 characters per line.
+    width: usize,
+
+    /// Line ending inserted between lines.
+    ending: LineEnding,
+
+    /// Number of Base64 characters written on the current line.
+    col: usize,
+}
+
+/// Number of Base64 characters needed to represent `n` buffered bytes
+/// (1 or 2) without padding.
+///
+/// Also used by the [`std` I/O adapters][`crate::EncodeWriter`] to flush
+/// a trailing partial block.
+pub(crate) fn tail_len(n: usize) -> usize {
+    match n {
+        1 => 2,
+        2 => 3,
+        _ => 0,
+    }
+}
+
+/// Base64 encode buffer for a partial 3-byte input group.
+///
+/// Also reused by the [`std` I/O adapters][`crate::EncodeWriter`].
+#[derive(Clone, Default)]
+pub(crate) struct BlockBuffer {
+    /// Up to 3 bytes of buffered input awaiting a complete group.
+    bytes: [u8; 3],
+
+    /// Number of bytes currently buffered.
+    length: usize,
+}
+
+impl BlockBuffer {
+    /// Buffer as much of `input` as fits, returning the number of bytes
+    /// consumed.
+    pub(crate) fn fill(&mut self, input: &[u8]) -> usize {
+        let n = input.len().min(3 - self.length);
+        self.bytes[self.length..][..n].copy_from_slice(&input[..n]);
+        self.length += n;
+        n
+    }
+
+    /// Is the buffer full (3 bytes)?
+    pub(crate) fn is_full(&self) -> bool {
+        self.length == 3
+    }
+
+    /// Is the buffer empty?
+    pub(crate) fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Number of bytes currently buffered.
+    #[cfg(feature = "std")]
+    pub(crate) fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Take the buffered bytes as a zero-padded 3-byte group, resetting
+    /// the buffer.
+    pub(crate) fn take(&mut self) -> [u8; 3] {
+        let mut bytes = self.bytes;
+
+        for byte in &mut bytes[self.length..] {
+            *byte = 0;
+        }
+
+        self.length = 0;
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Base64, Base64Unpadded, Encoder, LineEnding};
+
+    /// Padded Base64-encoded example
+    const PADDED_BASE64: &str =
+         "AAAAE2VjZHNhLXNoYTItbmlzdHAyNTYAAAAIbmlzdHAyNTYAAABBBHwf2HMM5TRXvo2SQJjsNkiDD5KqiiNjrGVv3UUh+mMT5RHxiRtOnlqvjhQtBq0VpmpCV/PwUdhOig4vkbqAcEc=";
+    const PADDED_BIN: &[u8] = &[
+        0, 0, 0, 19, 101, 99, 100, 115, 97, 45, 115, 104, 97, 50, 45, 110, 105, 115, 116, 112, 50,
+        53, 54, 0, 0, 0, 8, 110, 105, 115, 116, 112, 50, 53, 54, 0, 0, 0, 65, 4, 124, 31, 216, 115,
+        12, 229, 52, 87, 190, 141, 146, 64, 152, 236, 54, 72, 131, 15, 146, 170, 138, 35, 99, 172,
+        101, 111, 221, 69, 33, 250, 99, 19, 229, 17, 241, 137, 27, 78, 158, 90, 175, 142, 20, 45,
+        6, 173, 21, 166, 106, 66, 87, 243, 240, 81, 216, 78, 138, 14, 47, 145, 186, 128, 112, 71,
+    ];
+
+    /// Unpadded Base64-encoded example
+    const UNPADDED_BASE64: &str =
+        "AAAAC3NzaC1lZDI1NTE5AAAAILM+rvN+ot98qgEN796jTiQfZfG1KaT0PtFDJ/XFSqti";
+    const UNPADDED_BIN: &[u8] = &[
+        0, 0, 0, 11, 115, 115, 104, 45, 101, 100, 50, 53, 53, 49, 57, 0, 0, 0, 32, 179, 62, 174,
+        243, 126, 162, 223, 124, 170, 1, 13, 239, 222, 163, 78, 36, 31, 101, 241, 181, 41, 164,
+        244, 62, 209, 67, 39, 245, 197, 74, 171, 98,
+    ];
+
+    #[test]
+    fn encode_padded() {
+        for chunk_size in 1..=PADDED_BIN.len() {
+            let mut buffer = [0u8; 160];
+            let mut encoder = Encoder::<Base64>::new(&mut buffer);
+
+            for chunk in PADDED_BIN.chunks(chunk_size) {
+                encoder.encode(chunk).unwrap();
+            }
+
+            assert_eq!(encoder.finish().unwrap(), PADDED_BASE64);
+        }
+    }
+
+    #[test]
+    fn encode_unpadded() {
+        for chunk_size in 1..=UNPADDED_BIN.len() {
+            let mut buffer = [0u8; 96];
+            let mut encoder = Encoder::<Base64Unpadded>::new(&mut buffer);
+
+            for chunk in UNPADDED_BIN.chunks(chunk_size) {
+                encoder.encode(chunk).unwrap();
+            }
+
+            assert_eq!(encoder.finish().unwrap(), UNPADDED_BASE64);
+        }
+    }
+
+    /// Message used by the `new_wrapped` tests below.
+    const WRAPPED_BIN: &[u8] = b"Hello, World! This is a test of line wrapping.";
+
+    /// `WRAPPED_BIN` encoded as padded Base64, hard-wrapped at 5 Base64
+    /// characters per line (deliberately not a multiple of 4, so Base64
+    /// groups straddle line breaks) using `\n`. `Encoder::finish` always
+    /// emits a trailing line ending after the last line written.
+    const WRAPPED_LF: &str = "SGVsb\nG8sIF\ndvcmx\nkISBU\naGlzI\nGlzIG\nEgdGV\nzdCBv\nZiBsa\nW5lIH\ndyYXB\nwaW5n\nLg==\n";
+
+    /// Same as `WRAPPED_LF`, but with `\r\n` line endings.
+    const WRAPPED_CRLF: &str = "SGVsb\r\nG8sIF\r\ndvcmx\r\nkISBU\r\naGlzI\r\nGlzIG\r\nEgdGV\r\nzdCBv\r\nZiBsa\r\nW5lIH\r\ndyYXB\r\nwaW5n\r\nLg==\r\n";
+
+    const WRAPPED_WIDTH: usize = 5;
+
+    #[test]
+    fn encode_wrapped_lf() {
+        for chunk_size in 1..=WRAPPED_BIN.len() {
+            let mut buffer = [0u8; 128];
+            let mut encoder = Encoder::<Base64>::new_wrapped(&mut buffer, WRAPPED_WIDTH, LineEnding::Lf);
+
+            for chunk in WRAPPED_BIN.chunks(chunk_size) {
+                encoder.encode(chunk).unwrap();
+            }
+
+            assert_eq!(encoder.finish().unwrap(), WRAPPED_LF);
+        }
+    }
+
+    #[test]
+    fn encode_wrapped_crlf() {
+        for chunk_size in 1..=WRAPPED_BIN.len() {
+            let mut buffer = [0u8; 128];
+            let mut encoder =
+                Encoder::<Base64>::new_wrapped(&mut buffer, WRAPPED_WIDTH, LineEnding::Crlf);
+
+            for chunk in WRAPPED_BIN.chunks(chunk_size) {
+                encoder.encode(chunk).unwrap();
+            }
+
+            assert_eq!(encoder.finish().unwrap(), WRAPPED_CRLF);
+        }
+    }
+
+    #[test]
+    fn encode_wrapped_roundtrips_through_decoder() {
+        let mut buffer = [0u8; 128];
+        let mut encoder = Encoder::<Base64>::new_wrapped(&mut buffer, WRAPPED_WIDTH, LineEnding::Crlf);
+        encoder.encode(WRAPPED_BIN).unwrap();
+        let wrapped = encoder.finish().unwrap();
+
+        let mut decoder =
+            crate::Decoder::<Base64>::new_wrapped(wrapped.as_bytes(), WRAPPED_WIDTH).unwrap();
+        let mut decoded = [0u8; WRAPPED_BIN.len()];
+        assert_eq!(decoder.decode(&mut decoded).unwrap(), WRAPPED_BIN);
+    }
+}
@@ -0,0 +1,137 @@
+//! Traits for Base64 encodings.
+
+use crate::{variant, variant::Variant, Error};
+use core::str;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+
+/// Core encoder/decoder trait for this crate's Base64 variants.
+///
+/// This is impl'd for all of the crate's [`Variant`] types via a blanket
+/// impl, so it is the trait users should reach for (e.g. [`Base64`] or
+/// [`Base64Unpadded`]).
+///
+/// [`Base64`]: crate::Base64
+/// [`Base64Unpadded`]: crate::Base64Unpadded
+pub trait Encoding: Sized {
+    /// Decode a Base64 string into the provided destination buffer.
+    fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error>;
+
+    /// Decode a Base64 string into a byte vector.
+    #[cfg(feature = "alloc")]
+    fn decode_vec(input: &str) -> Result<Vec<u8>, Error>;
+
+    /// Encode the input byte slice as Base64, writing the result into the
+    /// provided destination buffer, and returning the encoded result as a
+    /// `&str`.
+    fn encode<'o>(src: &[u8], dst: &'o mut [u8]) -> Result<&'o str, Error>;
+
+    /// Encode the input byte slice as Base64, returning a `String`.
+    #[cfg(feature = "alloc")]
+    fn encode_string(input: &[u8]) -> String;
+
+    /// Get the length of Base64 produced by encoding the given bytes.
+    fn encoded_len(bytes: &[u8]) -> usize;
+}
+
+impl<E: Variant> Encoding for E {
+    fn decode(src: impl AsRef<[u8]>, dst: &mut [u8]) -> Result<&[u8], Error> {
+        let src = src.as_ref();
+
+        let src = if E::PADDED {
+            let unpadded_len = decode_padding(src)?;
+            &src[..unpadded_len]
+        } else {
+            src
+        };
+
+        variant::decode::<E>(src, dst)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn decode_vec(input: &str) -> Result<Vec<u8>, Error> {
+        let mut output = vec![0u8; input.len()];
+        let len = Self::decode(input.as_bytes(), &mut output)?.len();
+        output.truncate(len);
+        Ok(output)
+    }
+
+    fn encode<'o>(src: &[u8], dst: &'o mut [u8]) -> Result<&'o str, Error> {
+        let out_len = encoded_len::<E>(src.len());
+
+        if out_len > dst.len() {
+            return Err(Error::InvalidLength);
+        }
+
+        let raw_len = unpadded_len(src.len());
+        variant::encode::<E>(src, &mut dst[..raw_len])?;
+
+        if E::PADDED {
+            for byte in &mut dst[raw_len..out_len] {
+                *byte = b'=';
+            }
+        }
+
+        str::from_utf8(&dst[..out_len]).map_err(|_| Error::InvalidEncoding)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn encode_string(input: &[u8]) -> String {
+        let elen = encoded_len::<E>(input.len());
+        let mut dst = vec![0u8; elen];
+        let res = <E as Encoding>::encode(input, &mut dst).expect("encoding error");
+
+        debug_assert_eq!(elen, res.len());
+        String::from_utf8(dst).expect("UTF-8 encoding error")
+    }
+
+    fn encoded_len(bytes: &[u8]) -> usize {
+        encoded_len::<E>(bytes.len())
+    }
+}
+
+/// Length of the unpadded Base64 produced by encoding `len` bytes.
+pub(crate) fn unpadded_len(len: usize) -> usize {
+    let full_groups = len / 3;
+
+    full_groups * 4
+        + match len % 3 {
+            0 => 0,
+            1 => 2,
+            _ => 3,
+        }
+}
+
+/// Length of the Base64 produced by encoding `len` bytes with variant `E`,
+/// including `=` padding if `E::PADDED`.
+fn encoded_len<E: Variant>(len: usize) -> usize {
+    let raw = unpadded_len(len);
+
+    if E::PADDED {
+        raw.div_ceil(4) * 4
+    } else {
+        raw
+    }
+}
+
+/// Strip `=` padding off the end of a Base64 string, returning the length
+/// of the unpadded prefix.
+///
+/// Only a single trailing `=` or `==` is ever stripped; any further
+/// malformed padding (e.g. three or more `=` in a row, or a stray `=`
+/// elsewhere) is left in the returned prefix, where it's rejected when
+/// that prefix is run through the alphabet decode that follows.
+pub(crate) fn decode_padding(input: &[u8]) -> Result<usize, Error> {
+    if input.len() % 4 != 0 {
+        return Err(Error::InvalidLength);
+    }
+
+    let unpadded_len = match input {
+        [head @ .., b'=', b'='] if !head.is_empty() => input.len() - 2,
+        [head @ .., b'='] if !head.is_empty() => input.len() - 1,
+        _ => input.len(),
+    };
+
+    Ok(unpadded_len)
+}
@@ -0,0 +1,25 @@
+//! Error types
+
+use core::fmt;
+
+/// Error type.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Invalid encoding of provided Base64 string.
+    InvalidEncoding,
+
+    /// Insufficient output buffer length.
+    InvalidLength,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidEncoding => "invalid Base64 encoding",
+            Error::InvalidLength => "invalid Base64 length",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -0,0 +1,306 @@
+//! `std::io` adapters for streaming Base64, available under the `std`
+//! crate feature.
+
+use crate::{
+    encoder::{self, BlockBuffer},
+    variant::Variant,
+    Encoding, Error,
+};
+use std::io::{self, Read, Write};
+
+/// Size of the internal buffer [`DecodeReader`] uses to stage Base64 input
+/// read from the inner reader.
+///
+/// Always a multiple of 4 so it aligns on Base64 group boundaries.
+const RAW_BUF_LEN: usize = 1024;
+
+/// Size of the internal buffer [`DecodeReader`] decodes into before handing
+/// bytes back to callers of [`Read::read`].
+const DECODED_BUF_LEN: usize = RAW_BUF_LEN / 4 * 3;
+
+/// Adapter which decodes Base64 read from an inner reader on the fly.
+///
+/// Reuses the same [`Encoding`] machinery as the one-shot and [`Decoder`]
+/// APIs; buffering is bounded by [`RAW_BUF_LEN`]/[`DECODED_BUF_LEN`]
+/// regardless of how much data is read overall.
+///
+/// [`Decoder`]: crate::Decoder
+pub struct DecodeReader<E: Variant, R: Read> {
+    /// Reader yielding Base64-encoded bytes.
+    inner: R,
+
+    /// Base64 input bytes read from `inner` but not yet decoded.
+    raw: [u8; RAW_BUF_LEN],
+
+    /// Number of valid bytes at the start of `raw`.
+    raw_len: usize,
+
+    /// Decoded bytes not yet returned to the caller.
+    decoded: [u8; DECODED_BUF_LEN],
+
+    /// Number of valid bytes at the start of `decoded`.
+    decoded_len: usize,
+
+    /// Position of the next unread byte in `decoded`.
+    decoded_pos: usize,
+
+    /// Phantom parameter for the Base64 encoding in use.
+    encoding: core::marker::PhantomData<E>,
+}
+
+impl<E: Variant, R: Read> DecodeReader<E, R> {
+    /// Create a new [`DecodeReader`] which decodes Base64 bytes read from
+    /// `inner`.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            raw: [0u8; RAW_BUF_LEN],
+            raw_len: 0,
+            decoded: [0u8; DECODED_BUF_LEN],
+            decoded_len: 0,
+            decoded_pos: 0,
+            encoding: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<E: Variant, R: Read> Read for DecodeReader<E, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.decoded_pos == self.decoded_len {
+            self.decoded_pos = 0;
+            self.decoded_len = 0;
+
+            while self.decoded_len == 0 {
+                let n = self.inner.read(&mut self.raw[self.raw_len..])?;
+
+                if n == 0 {
+                    if self.raw_len == 0 {
+                        return Ok(0);
+                    }
+
+                    let decoded = E::decode(&self.raw[..self.raw_len], &mut self.decoded)
+                        .map_err(invalid_data)?;
+                    self.decoded_len = decoded.len();
+                    self.raw_len = 0;
+                    break;
+                }
+
+                self.raw_len += n;
+
+                // Hold back the trailing block in case it turns out to be
+                // the final (possibly padded) one; only the blocks before
+                // it are guaranteed not to contain padding.
+                let usable = self.raw_len.saturating_sub(4) / 4 * 4;
+
+                if usable == 0 {
+                    continue;
+                }
+
+                let decoded = E::Unpadded::decode(&self.raw[..usable], &mut self.decoded)
+                    .map_err(invalid_data)?;
+                self.decoded_len = decoded.len();
+
+                self.raw.copy_within(usable..self.raw_len, 0);
+                self.raw_len -= usable;
+            }
+        }
+
+        let n = buf.len().min(self.decoded_len - self.decoded_pos);
+        buf[..n].copy_from_slice(&self.decoded[self.decoded_pos..][..n]);
+        self.decoded_pos += n;
+        Ok(n)
+    }
+}
+
+/// Adapter which encodes bytes written to it as Base64, writing the result
+/// to an inner writer on the fly.
+///
+/// Reuses the same [`BlockBuffer`] accumulator as the [`Encoder`]
+/// streaming API, so `write` calls of any size can be mixed freely.
+///
+/// [`Encoder`]: crate::Encoder
+pub struct EncodeWriter<E: Variant, W: Write> {
+    /// Writer receiving Base64-encoded bytes.
+    inner: W,
+
+    /// Block buffer used for input which isn't a multiple of 3 bytes.
+    buffer: BlockBuffer,
+
+    /// Phantom parameter for the Base64 encoding in use.
+    encoding: core::marker::PhantomData<E>,
+}
+
+impl<E: Variant, W: Write> EncodeWriter<E, W> {
+    /// Create a new [`EncodeWriter`] which writes Base64-encoded output to
+    /// `inner`.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            buffer: BlockBuffer::default(),
+            encoding: core::marker::PhantomData,
+        }
+    }
+
+    /// Flush any buffered input, emitting padding if `E::PADDED`, and
+    /// return the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if !self.buffer.is_empty() {
+            let tail_chars = encoder::tail_len(self.buffer.len());
+            let tail = self.buffer.take();
+            let encoded = E::Unpadded::encode_group(tail);
+            self.inner.write_all(&encoded[..tail_chars])?;
+
+            if E::PADDED {
+                self.inner.write_all(&[b'=', b'='][..4 - tail_chars])?;
+            }
+        }
+
+        Ok(self.inner)
+    }
+
+    /// Encode one complete 3-byte group, writing 4 Base64 characters.
+    fn write_group(&mut self, group: [u8; 3]) -> io::Result<()> {
+        let encoded = E::Unpadded::encode_group(group);
+        self.inner.write_all(&encoded)
+    }
+}
+
+impl<E: Variant, W: Write> Write for EncodeWriter<E, W> {
+    fn write(&mut self, mut input: &[u8]) -> io::Result<usize> {
+        let total = input.len();
+
+        if !self.buffer.is_empty() {
+            let n = self.buffer.fill(input);
+            input = &input[n..];
+
+            if self.buffer.is_full() {
+                let group = self.buffer.take();
+                self.write_group(group)?;
+            } else {
+                return Ok(total);
+            }
+        }
+
+        let mut chunks = input.chunks_exact(3);
+
+        for chunk in &mut chunks {
+            self.write_group([chunk[0], chunk[1], chunk[2]])?;
+        }
+
+        let rem = chunks.remainder();
+        debug_assert!(self.buffer.is_empty());
+        self.buffer.fill(rem);
+
+        Ok(total)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Wrap a [`crate::Error`] as an [`io::Error`].
+fn invalid_data(err: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecodeReader, EncodeWriter};
+    use crate::{Base64, Encoding};
+    use std::{
+        io::{Read, Write},
+        vec,
+        vec::Vec,
+    };
+
+    /// Example binary message, sized so its Base64 encoding spans several
+    /// [`RAW_BUF_LEN`][`super::RAW_BUF_LEN`]-sized reads.
+    const BIN: &[u8] = &[
+        0, 0, 0, 19, 101, 99, 100, 115, 97, 45, 115, 104, 97, 50, 45, 110, 105, 115, 116, 112, 50,
+        53, 54, 0, 0, 0, 8, 110, 105, 115, 116, 112, 50, 53, 54, 0, 0, 0, 65, 4, 124, 31, 216, 115,
+        12, 229, 52, 87, 190, 141, 146, 64, 152, 236, 54, 72, 131, 15, 146, 170, 138, 35, 99, 172,
+        101, 111, 221, 69, 33, 250, 99, 19, 229, 17, 241, 137, 27, 78, 158, 90, 175, 142, 20, 45,
+        6, 173, 21, 166, 106, 66, 87, 243, 240, 81, 216, 78, 138, 14, 47, 145, 186, 128, 112, 71,
+    ];
+
+    /// A [`Read`] impl which hands back at most `chunk` bytes per call,
+    /// used to exercise [`DecodeReader`]'s buffering with small/partial
+    /// reads instead of one big read from a [`std::io::Cursor`].
+    struct ChunkedReader<'a> {
+        data: &'a [u8],
+        pos: usize,
+        chunk: usize,
+    }
+
+    impl Read for ChunkedReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.chunk).min(self.data.len() - self.pos);
+            buf[..n].copy_from_slice(&self.data[self.pos..][..n]);
+            self.pos += n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn decode_reader_roundtrip_small_reads() {
+        let mut encoded_buf = [0u8; 256];
+        let encoded = Base64::encode(BIN, &mut encoded_buf).unwrap();
+
+        for read_chunk in [1, 2, 3, 4, 7, 16] {
+            for out_chunk in [1, 2, 3, 5] {
+                let mut reader = DecodeReader::<Base64, _>::new(ChunkedReader {
+                    data: encoded.as_bytes(),
+                    pos: 0,
+                    chunk: read_chunk,
+                });
+
+                let mut decoded = Vec::new();
+                let mut buf = vec![0u8; out_chunk];
+
+                loop {
+                    let n = reader.read(&mut buf).unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    decoded.extend_from_slice(&buf[..n]);
+                }
+
+                assert_eq!(decoded, BIN, "read_chunk={read_chunk} out_chunk={out_chunk}");
+            }
+        }
+    }
+
+    #[test]
+    fn decode_reader_rejects_invalid_input() {
+        let mut reader = DecodeReader::<Base64, _>::new(&b"not valid base64!!"[..]);
+        let mut buf = [0u8; 32];
+        let err = reader.read(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn encode_writer_roundtrip_small_writes() {
+        let mut encoded_buf = [0u8; 256];
+        let expected = Base64::encode(BIN, &mut encoded_buf).unwrap();
+
+        for write_chunk in [1, 2, 3, 4, 7, 16] {
+            let mut sink = Vec::new();
+            {
+                let mut writer = EncodeWriter::<Base64, _>::new(&mut sink);
+
+                for chunk in BIN.chunks(write_chunk) {
+                    writer.write_all(chunk).unwrap();
+                }
+
+                writer.finish().unwrap();
+            }
+
+            assert_eq!(
+                core::str::from_utf8(&sink).unwrap(),
+                expected,
+                "write_chunk={write_chunk}"
+            );
+        }
+    }
+}
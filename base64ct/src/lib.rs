@@ -71,18 +71,28 @@ extern crate alloc;
 extern crate std;
 
 mod decoder;
+mod display;
+mod encoder;
 mod encoding;
 mod errors;
+#[cfg(feature = "std")]
+mod io;
 mod variant;
 
 pub use crate::{
     decoder::Decoder,
+    display::Base64Display,
+    encoder::{Encoder, LineEnding, DEFAULT_LINE_WIDTH},
     encoding::Encoding,
-    errors::{Error, InvalidEncodingError, InvalidLengthError},
+    errors::Error,
     variant::{
         bcrypt::Base64Bcrypt,
         crypt::Base64Crypt,
+        sha_crypt::Base64ShaCrypt,
         standard::{Base64, Base64Unpadded},
         url::{Base64Url, Base64UrlUnpadded},
     },
 };
+
+#[cfg(feature = "std")]
+pub use crate::io::{DecodeReader, EncodeWriter};
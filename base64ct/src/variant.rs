@@ -0,0 +1,186 @@
+//! Base64 variants (alphabet/padding combinations).
+
+pub(crate) mod bcrypt;
+pub(crate) mod crypt;
+pub(crate) mod sha_crypt;
+pub(crate) mod standard;
+pub(crate) mod url;
+
+use crate::Error;
+use core::str;
+
+/// Sealed trait implemented by all of this crate's Base64 variants.
+///
+/// This is an implementation detail and not intended to be used directly.
+/// Use the [`Encoding`][`crate::Encoding`] trait instead, which has a
+/// blanket impl for all types which impl `Variant`.
+pub trait Variant: private::Sealed + Copy + Sized {
+    /// Is this encoding padded?
+    const PADDED: bool;
+
+    /// Unpadded version of this variant's alphabet, used internally to
+    /// decode/encode whole 3-byte/4-char groups without branching on
+    /// padding.
+    type Unpadded: Variant;
+
+    /// Decode a single Base64 character into its 6-bit value.
+    ///
+    /// Returns `-1` if the character is not part of this variant's
+    /// alphabet. Implemented using branchless bitwise arithmetic so
+    /// decoding never branches on the (potentially secret) input byte.
+    fn decode_6bits(byte: u8) -> i16;
+
+    /// Encode a 6-bit value as a single Base64 character.
+    ///
+    /// Implemented using branchless bitwise arithmetic so encoding never
+    /// branches on the (potentially secret) input byte.
+    fn encode_6bits(src: u8) -> u8;
+
+    /// Assemble 4 already-decoded 6-bit values into up to 3 bytes.
+    ///
+    /// The default groups bits MSB-first, which is what every RFC 4648
+    /// alphabet as well as the traditional Unix `crypt` alphabet use.
+    /// Variants with a different bit order (e.g. `sha-crypt`) override
+    /// this.
+    fn decode_group(src: [i16; 4]) -> [u8; 3] {
+        [
+            ((src[0] << 2) | (src[1] >> 4)) as u8,
+            ((src[1] << 4) | (src[2] >> 2)) as u8,
+            ((src[2] << 6) | src[3]) as u8,
+        ]
+    }
+
+    /// Split up to 3 bytes (zero-padded) into 4 Base64 characters.
+    ///
+    /// The default groups bits MSB-first; see [`Variant::decode_group`].
+    fn encode_group(src: [u8; 3]) -> [u8; 4] {
+        [
+            Self::encode_6bits(src[0] >> 2),
+            Self::encode_6bits((src[0] << 4 | src[1] >> 4) & 0x3f),
+            Self::encode_6bits((src[1] << 2 | src[2] >> 6) & 0x3f),
+            Self::encode_6bits(src[2] & 0x3f),
+        ]
+    }
+}
+
+/// Decode a contiguous, unpadded Base64-encoded slice into `dst`, returning
+/// the decoded bytes.
+///
+/// This is the workhorse behind the blanket [`Encoding`][`crate::Encoding`]
+/// impl as well as the stateful [`Decoder`][`crate::Decoder`]; padding is
+/// stripped by the caller beforehand.
+pub(crate) fn decode<'o, E: Variant>(src: &[u8], dst: &'o mut [u8]) -> Result<&'o [u8], Error> {
+    let full_groups = src.len() / 4;
+
+    let tail_len = match src.len() % 4 {
+        0 => 0,
+        2 => 1,
+        3 => 2,
+        _ => return Err(Error::InvalidEncoding),
+    };
+
+    let out_len = full_groups
+        .checked_mul(3)
+        .and_then(|n| n.checked_add(tail_len))
+        .ok_or(Error::InvalidLength)?;
+
+    if out_len > dst.len() {
+        return Err(Error::InvalidLength);
+    }
+
+    let mut err: i16 = 0;
+
+    for i in 0..full_groups {
+        let (decoded, e) = decode_block::<E>(&src[i * 4..][..4]);
+        dst[i * 3..][..3].copy_from_slice(&decoded);
+        err |= e;
+    }
+
+    if tail_len > 0 {
+        let tail = &src[full_groups * 4..];
+
+        let mut block = [b'A'; 4];
+        block[..tail.len()].copy_from_slice(tail);
+
+        let (decoded, e) = decode_block::<E>(&block);
+        err |= e;
+
+        dst[full_groups * 3..][..tail_len].copy_from_slice(&decoded[..tail_len]);
+    }
+
+    if err != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+
+    Ok(&dst[..out_len])
+}
+
+/// Encode a byte slice as Base64 into `dst`, without padding.
+///
+/// This is the workhorse behind the blanket [`Encoding`][`crate::Encoding`]
+/// impl as well as the streaming [`Encoder`][`crate::Encoder`]; padding is
+/// added by the caller afterward.
+pub(crate) fn encode<'o, E: Variant>(src: &[u8], dst: &'o mut [u8]) -> Result<&'o str, Error> {
+    let full_groups = src.len() / 3;
+    let tail_len = src.len() % 3;
+
+    let out_len = full_groups
+        .checked_mul(4)
+        .and_then(|n| n.checked_add(if tail_len == 0 { 0 } else { tail_len + 1 }))
+        .ok_or(Error::InvalidLength)?;
+
+    if out_len > dst.len() {
+        return Err(Error::InvalidLength);
+    }
+
+    for i in 0..full_groups {
+        let group = [src[i * 3], src[i * 3 + 1], src[i * 3 + 2]];
+        dst[i * 4..][..4].copy_from_slice(&E::encode_group(group));
+    }
+
+    if tail_len > 0 {
+        let tail = &src[full_groups * 3..];
+
+        let mut block = [0u8; 3];
+        block[..tail.len()].copy_from_slice(tail);
+        let encoded = E::encode_group(block);
+
+        dst[full_groups * 4..][..tail_len + 1].copy_from_slice(&encoded[..tail_len + 1]);
+    }
+
+    str::from_utf8(&dst[..out_len]).map_err(|_| Error::InvalidEncoding)
+}
+
+/// Mask which is all-ones (-1 as `i16`) when `a == b`, and all-zeroes
+/// otherwise. Used by variant alphabets to build branchless lookups.
+fn mask_eq(a: i16, b: i16) -> i16 {
+    -(((a ^ b) == 0) as i16)
+}
+
+/// Mask which is all-ones (-1 as `i16`) when `lo <= c <= hi`, and
+/// all-zeroes otherwise. Used by variant alphabets to build branchless
+/// lookups.
+fn mask_range(c: i16, lo: i16, hi: i16) -> i16 {
+    -((((c - lo) as u16) <= ((hi - lo) as u16)) as i16)
+}
+
+/// Decode a single 4-character group, returning the decoded bytes together
+/// with a branchless error accumulator (nonzero if any character was
+/// invalid).
+fn decode_block<E: Variant>(src: &[u8]) -> ([u8; 3], i16) {
+    debug_assert_eq!(src.len(), 4);
+
+    let c0 = E::decode_6bits(src[0]);
+    let c1 = E::decode_6bits(src[1]);
+    let c2 = E::decode_6bits(src[2]);
+    let c3 = E::decode_6bits(src[3]);
+
+    let err = (c0 | c1 | c2 | c3) >> 8;
+    (E::decode_group([c0, c1, c2, c3]), err)
+}
+
+pub(crate) mod private {
+    /// Sealing trait so [`super::Variant`] cannot be implemented outside
+    /// this crate.
+    pub trait Sealed {}
+}
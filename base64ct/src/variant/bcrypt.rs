@@ -0,0 +1,68 @@
+//! `bcrypt`'s nonstandard Base64 encoding.
+
+use super::{mask_eq, mask_range, private, Variant};
+
+/// `bcrypt`-flavored Base64 encoding (unpadded).
+///
+/// ```text
+/// ./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base64Bcrypt;
+
+impl private::Sealed for Base64Bcrypt {}
+
+impl Variant for Base64Bcrypt {
+    const PADDED: bool = false;
+    type Unpadded = Base64Bcrypt;
+
+    fn decode_6bits(byte: u8) -> i16 {
+        decode_6bits(byte)
+    }
+
+    fn encode_6bits(src: u8) -> u8 {
+        encode_6bits(src)
+    }
+}
+
+/// Decode a single Base64 character into its 6-bit value.
+fn decode_6bits(byte: u8) -> i16 {
+    let c = byte as i16;
+    let mut out = 0i16;
+    let mut valid = 0i16;
+
+    let m = mask_eq(c, 0x2e); // '.' decodes to 0, so `out` is unaffected
+    valid |= m;
+
+    let m = mask_eq(c, 0x2f); // '/'
+    out |= m & 1;
+    valid |= m;
+
+    let m = mask_range(c, 0x41, 0x5a); // 'A'..='Z'
+    out |= m & (c - 0x41 + 2);
+    valid |= m;
+
+    let m = mask_range(c, 0x61, 0x7a); // 'a'..='z'
+    out |= m & (c - 0x61 + 28);
+    valid |= m;
+
+    let m = mask_range(c, 0x30, 0x39); // '0'..='9'
+    out |= m & (c - 0x30 + 54);
+    valid |= m;
+
+    (out & valid) | !valid
+}
+
+/// Encode a 6-bit value as a single Base64 character.
+fn encode_6bits(src: u8) -> u8 {
+    let s = src as i16;
+    let mut out = 0i16;
+
+    out |= mask_eq(s, 0) & 0x2e;
+    out |= mask_eq(s, 1) & 0x2f;
+    out |= mask_range(s, 2, 27) & (s - 2 + 0x41);
+    out |= mask_range(s, 28, 53) & (s - 28 + 0x61);
+    out |= mask_range(s, 54, 63) & (s - 54 + 0x30);
+
+    out as u8
+}
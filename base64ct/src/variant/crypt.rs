@@ -0,0 +1,69 @@
+//! Traditional Unix `crypt(3)` Base64 encoding, e.g. as used by `bcrypt`'s
+//! predecessor and other classic password hash formats.
+
+use super::{mask_eq, mask_range, private, Variant};
+
+/// Traditional Unix `crypt(3)` Base64 encoding (unpadded).
+///
+/// ```text
+/// ./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base64Crypt;
+
+impl private::Sealed for Base64Crypt {}
+
+impl Variant for Base64Crypt {
+    const PADDED: bool = false;
+    type Unpadded = Base64Crypt;
+
+    fn decode_6bits(byte: u8) -> i16 {
+        decode_6bits(byte)
+    }
+
+    fn encode_6bits(src: u8) -> u8 {
+        encode_6bits(src)
+    }
+}
+
+/// Decode a single Base64 character into its 6-bit value.
+pub(super) fn decode_6bits(byte: u8) -> i16 {
+    let c = byte as i16;
+    let mut out = 0i16;
+    let mut valid = 0i16;
+
+    let m = mask_eq(c, 0x2e); // '.' decodes to 0, so `out` is unaffected
+    valid |= m;
+
+    let m = mask_eq(c, 0x2f); // '/'
+    out |= m & 1;
+    valid |= m;
+
+    let m = mask_range(c, 0x30, 0x39); // '0'..='9'
+    out |= m & (c - 0x30 + 2);
+    valid |= m;
+
+    let m = mask_range(c, 0x41, 0x5a); // 'A'..='Z'
+    out |= m & (c - 0x41 + 12);
+    valid |= m;
+
+    let m = mask_range(c, 0x61, 0x7a); // 'a'..='z'
+    out |= m & (c - 0x61 + 38);
+    valid |= m;
+
+    (out & valid) | !valid
+}
+
+/// Encode a 6-bit value as a single Base64 character.
+pub(super) fn encode_6bits(src: u8) -> u8 {
+    let s = src as i16;
+    let mut out = 0i16;
+
+    out |= mask_eq(s, 0) & 0x2e;
+    out |= mask_eq(s, 1) & 0x2f;
+    out |= mask_range(s, 2, 11) & (s - 2 + 0x30);
+    out |= mask_range(s, 12, 37) & (s - 12 + 0x41);
+    out |= mask_range(s, 38, 63) & (s - 38 + 0x61);
+
+    out as u8
+}
@@ -0,0 +1,80 @@
+//! `sha-crypt` Base64 encoding, as used by the glibc `$5$`/`$6$` password
+//! hash formats.
+
+use super::{crypt, private, Variant};
+
+/// `sha-crypt` ($5$/$6$) Base64 encoding (unpadded).
+///
+/// Uses the same alphabet as [`Base64Crypt`][`crate::Base64Crypt`] but
+/// packs each 3-byte/4-character group in little-endian order rather than
+/// the MSB-first order RFC 4648 and `crypt(3)` use.
+///
+/// ```text
+/// ./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base64ShaCrypt;
+
+impl private::Sealed for Base64ShaCrypt {}
+
+impl Variant for Base64ShaCrypt {
+    const PADDED: bool = false;
+    type Unpadded = Base64ShaCrypt;
+
+    fn decode_6bits(byte: u8) -> i16 {
+        crypt::decode_6bits(byte)
+    }
+
+    fn encode_6bits(src: u8) -> u8 {
+        crypt::encode_6bits(src)
+    }
+
+    fn decode_group(src: [i16; 4]) -> [u8; 3] {
+        let v = src[0] as u32 | (src[1] as u32) << 6 | (src[2] as u32) << 12 | (src[3] as u32) << 18;
+        [v as u8, (v >> 8) as u8, (v >> 16) as u8]
+    }
+
+    fn encode_group(src: [u8; 3]) -> [u8; 4] {
+        let v = src[0] as u32 | (src[1] as u32) << 8 | (src[2] as u32) << 16;
+        [
+            Self::encode_6bits((v & 0x3f) as u8),
+            Self::encode_6bits(((v >> 6) & 0x3f) as u8),
+            Self::encode_6bits(((v >> 12) & 0x3f) as u8),
+            Self::encode_6bits(((v >> 18) & 0x3f) as u8),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Base64ShaCrypt;
+    use crate::Encoding;
+
+    /// Known-answer vectors covering each tail length (0/1/2/3 leftover
+    /// bytes), confirming the little-endian group order is wired up
+    /// correctly in both directions.
+    const VECTORS: &[(&[u8], &str)] = &[
+        (&[], ""),
+        (&[0x00, 0x01, 0x02], ".2U."),
+        (&[0xff], "z1"),
+        (&[0xff, 0x00], "z1."),
+        (&[0x01, 0x02, 0x03, 0x04], "/6k.2."),
+        (b"sha", "nVKM"),
+    ];
+
+    #[test]
+    fn known_answer_encode() {
+        for (bin, b64) in VECTORS {
+            let mut buf = [0u8; 16];
+            assert_eq!(Base64ShaCrypt::encode(bin, &mut buf).unwrap(), *b64);
+        }
+    }
+
+    #[test]
+    fn known_answer_decode() {
+        for (bin, b64) in VECTORS {
+            let mut buf = [0u8; 16];
+            assert_eq!(Base64ShaCrypt::decode(b64, &mut buf).unwrap(), *bin);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Standard Base64 (RFC 4648 §4) with and without padding.
+
+use super::{mask_eq, mask_range, private, Variant};
+
+/// Standard Base64 encoding with `=` padding, as described in
+/// [RFC 4648 §4](https://tools.ietf.org/html/rfc4648#section-4).
+///
+/// ```text
+/// ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base64;
+
+impl private::Sealed for Base64 {}
+
+impl Variant for Base64 {
+    const PADDED: bool = true;
+    type Unpadded = Base64Unpadded;
+
+    fn decode_6bits(byte: u8) -> i16 {
+        decode_6bits(byte)
+    }
+
+    fn encode_6bits(src: u8) -> u8 {
+        encode_6bits(src)
+    }
+}
+
+/// Standard Base64 encoding without padding.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Base64Unpadded;
+
+impl private::Sealed for Base64Unpadded {}
+
+impl Variant for Base64Unpadded {
+    const PADDED: bool = false;
+    type Unpadded = Base64Unpadded;
+
+    fn decode_6bits(byte: u8) -> i16 {
+        decode_6bits(byte)
+    }
+
+    fn encode_6bits(src: u8) -> u8 {
+        encode_6bits(src)
+    }
+}
+
+/// Decode a single Base64 character into its 6-bit value.
+pub(super) fn decode_6bits(byte: u8) -> i16 {
+    let c = byte as i16;
+    let mut out = 0i16;
+    let mut valid = 0i16;
+
+    let m = mask_range(c, 0x41, 0x5a); // 'A'..='Z'
+    out |= m & (c - 0x41);
+    valid |= m;
+
+    let m = mask_range(c, 0x61, 0x7a); // 'a'..='z'
+    out |= m & (c - 0x61 + 26);
+    valid |= m;
+
+    let m = mask_range(c, 0x30, 0x39); // '0'..='9'
+    out |= m & (c - 0x30 + 52);
+    valid |= m;
+
+    let m = mask_eq(c, 0x2b); // '+'
+    out |= m & 62;
+    valid |= m;
+
+    let m = mask_eq(c, 0x2f); // '/'
+    out |= m & 63;
+    valid |= m;
+
+    (out & valid) | !valid
+}
+
+/// Encode a 6-bit value as a single Base64 character.
+pub(super) fn encode_6bits(src: u8) -> u8 {
+    let s = src as i16;
+    let mut out = 0i16;
+
+    out |= mask_range(s, 0, 25) & (s + 0x41);
+    out |= mask_range(s, 26, 51) & (s - 26 + 0x61);
+    out |= mask_range(s, 52, 61) & (s - 52 + 0x30);
+    out |= mask_eq(s, 62) & 0x2b;
+    out |= mask_eq(s, 63) & 0x2f;
+
+    out as u8
+}
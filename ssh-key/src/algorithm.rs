@@ -12,6 +12,18 @@ const ECDSA_SHA2_P384: &str = "ecdsa-sha2-nistp384";
 /// ECDSA with SHA-256 + NIST P-256
 const ECDSA_SHA2_P521: &str = "ecdsa-sha2-nistp521";
 
+/// RSA with SHA-256 (used for signing only; key type is still `ssh-rsa`)
+const RSA_SHA2_256: &str = "rsa-sha2-256";
+
+/// RSA with SHA-512 (used for signing only; key type is still `ssh-rsa`)
+const RSA_SHA2_512: &str = "rsa-sha2-512";
+
+/// FIDO/U2F security key with ECDSA + NIST P-256
+const SK_ECDSA_SHA2_P256: &str = "sk-ecdsa-sha2-nistp256@openssh.com";
+
+/// FIDO/U2F security key with Ed25519
+const SK_SSH_ED25519: &str = "sk-ssh-ed25519@openssh.com";
+
 /// Digital Signature Algorithm
 const SSH_DSA: &str = "ssh-dss";
 
@@ -39,11 +51,18 @@ pub enum Algorithm {
 
     /// RSA
     Rsa,
+
+    /// RSA with the given hash algorithm, as used by the `rsa-sha2-256`/
+    /// `rsa-sha2-512` signature algorithms.
+    RsaSha2(HashAlg),
+
+    /// FIDO/U2F hardware security key (`sk-*@openssh.com`)
+    SecurityKey(SecurityKeyAlgorithm),
 }
 
 impl Algorithm {
     /// Maximum size of algorithms known to this crate in bytes.
-    const MAX_SIZE: usize = 20;
+    const MAX_SIZE: usize = 35;
 
     /// Decode algorithm from the given string identifier.
     ///
@@ -52,6 +71,10 @@ impl Algorithm {
     /// - `ecdsa-sha2-nistp256`
     /// - `ecdsa-sha2-nistp384`
     /// - `ecdsa-sha2-nistp521`
+    /// - `rsa-sha2-256`
+    /// - `rsa-sha2-512`
+    /// - `sk-ecdsa-sha2-nistp256@openssh.com`
+    /// - `sk-ssh-ed25519@openssh.com`
     /// - `ssh-dss`
     /// - `ssh-ed25519`
     /// - `ssh-rsa`
@@ -60,6 +83,12 @@ impl Algorithm {
             ECDSA_SHA2_P256 => Ok(Algorithm::Ecdsa(EcdsaCurve::NistP256)),
             ECDSA_SHA2_P384 => Ok(Algorithm::Ecdsa(EcdsaCurve::NistP384)),
             ECDSA_SHA2_P521 => Ok(Algorithm::Ecdsa(EcdsaCurve::NistP521)),
+            RSA_SHA2_256 => Ok(Algorithm::RsaSha2(HashAlg::Sha256)),
+            RSA_SHA2_512 => Ok(Algorithm::RsaSha2(HashAlg::Sha512)),
+            SK_ECDSA_SHA2_P256 => Ok(Algorithm::SecurityKey(
+                SecurityKeyAlgorithm::EcdsaSha2NistP256,
+            )),
+            SK_SSH_ED25519 => Ok(Algorithm::SecurityKey(SecurityKeyAlgorithm::Ed25519)),
             SSH_DSA => Ok(Algorithm::Dsa),
             SSH_ED25519 => Ok(Algorithm::Ed25519),
             SSH_RSA => Ok(Algorithm::Rsa),
@@ -76,6 +105,9 @@ impl Algorithm {
             Algorithm::Ecdsa(EcdsaCurve::NistP521) => ECDSA_SHA2_P521,
             Algorithm::Ed25519 => SSH_ED25519,
             Algorithm::Rsa => SSH_RSA,
+            Algorithm::RsaSha2(HashAlg::Sha256) => RSA_SHA2_256,
+            Algorithm::RsaSha2(HashAlg::Sha512) => RSA_SHA2_512,
+            Algorithm::SecurityKey(alg) => alg.as_str(),
         }
     }
 
@@ -94,9 +126,15 @@ impl Algorithm {
         self == Algorithm::Ed25519
     }
 
-    /// Is the algorithm RSA?
+    /// Is the algorithm RSA, whether signed with the legacy SHA-1
+    /// algorithm or an `rsa-sha2-256`/`rsa-sha2-512` variant?
     pub fn is_rsa(self) -> bool {
-        self == Algorithm::Rsa
+        matches!(self, Algorithm::Rsa | Algorithm::RsaSha2(_))
+    }
+
+    /// Is the algorithm a FIDO/U2F hardware security key (`sk-*@openssh.com`)?
+    pub fn is_security_key(self) -> bool {
+        matches!(self, Algorithm::SecurityKey(_))
     }
 
     /// Decode algorithm using the supplied Base64 decoder.
@@ -184,3 +222,102 @@ impl str::FromStr for EcdsaCurve {
         EcdsaCurve::new(id)
     }
 }
+
+/// Hash algorithms used by the `rsa-sha2-256`/`rsa-sha2-512` signature
+/// algorithms.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum HashAlg {
+    /// SHA-256
+    Sha256,
+
+    /// SHA-512
+    Sha512,
+}
+
+/// FIDO/U2F hardware security key algorithms, as used by the
+/// `sk-*@openssh.com` key types.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum SecurityKeyAlgorithm {
+    /// ECDSA with SHA-256 + NIST P-256
+    EcdsaSha2NistP256,
+
+    /// Ed25519
+    Ed25519,
+}
+
+impl SecurityKeyAlgorithm {
+    /// Decode security key algorithm from the given string identifier.
+    ///
+    /// # Supported algorithms
+    ///
+    /// - `sk-ecdsa-sha2-nistp256@openssh.com`
+    /// - `sk-ssh-ed25519@openssh.com`
+    pub fn new(id: &str) -> Result<Self> {
+        match id {
+            SK_ECDSA_SHA2_P256 => Ok(SecurityKeyAlgorithm::EcdsaSha2NistP256),
+            SK_SSH_ED25519 => Ok(SecurityKeyAlgorithm::Ed25519),
+            _ => Err(Error::Algorithm),
+        }
+    }
+
+    /// Get the string identifier which corresponds to this algorithm.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SecurityKeyAlgorithm::EcdsaSha2NistP256 => SK_ECDSA_SHA2_P256,
+            SecurityKeyAlgorithm::Ed25519 => SK_SSH_ED25519,
+        }
+    }
+}
+
+impl fmt::Display for SecurityKeyAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl str::FromStr for SecurityKeyAlgorithm {
+    type Err = Error;
+
+    fn from_str(id: &str) -> Result<Self> {
+        SecurityKeyAlgorithm::new(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Algorithm, EcdsaCurve, HashAlg, SecurityKeyAlgorithm};
+
+    #[test]
+    fn roundtrips_rsa_sha2_identifiers() {
+        for id in ["rsa-sha2-256", "rsa-sha2-512"] {
+            assert_eq!(Algorithm::new(id).unwrap().as_str(), id);
+        }
+    }
+
+    #[test]
+    fn roundtrips_security_key_identifiers() {
+        for id in [
+            "sk-ecdsa-sha2-nistp256@openssh.com",
+            "sk-ssh-ed25519@openssh.com",
+        ] {
+            assert_eq!(Algorithm::new(id).unwrap().as_str(), id);
+        }
+    }
+
+    #[test]
+    fn is_rsa() {
+        assert!(Algorithm::Rsa.is_rsa());
+        assert!(Algorithm::RsaSha2(HashAlg::Sha256).is_rsa());
+        assert!(Algorithm::RsaSha2(HashAlg::Sha512).is_rsa());
+        assert!(!Algorithm::Ed25519.is_rsa());
+        assert!(!Algorithm::Ecdsa(EcdsaCurve::NistP256).is_rsa());
+    }
+
+    #[test]
+    fn is_security_key() {
+        assert!(Algorithm::SecurityKey(SecurityKeyAlgorithm::EcdsaSha2NistP256).is_security_key());
+        assert!(Algorithm::SecurityKey(SecurityKeyAlgorithm::Ed25519).is_security_key());
+        assert!(!Algorithm::Ed25519.is_security_key());
+        assert!(!Algorithm::Rsa.is_security_key());
+    }
+}